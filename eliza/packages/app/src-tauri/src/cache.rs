@@ -0,0 +1,143 @@
+// Persists the resolved trading-brain project path so we don't have to
+// re-run the full filesystem scan in `find_project_directory()` on every
+// launch. The cache is invalidated by a TTL and by re-validating the path.
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app_data_dir;
+
+/// Cache entries older than this are treated as stale even if the path
+/// still looks valid, so we periodically re-confirm the full scan.
+const CACHE_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+const STATE_FILE_NAME: &str = "project-cache.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedProject {
+    project_path: PathBuf,
+    package_json_mtime: u64,
+    cached_at: u64,
+}
+
+fn state_file_path() -> Option<PathBuf> {
+    Some(app_data_dir()?.join(STATE_FILE_NAME))
+}
+
+fn package_json_mtime(project_path: &Path) -> Option<u64> {
+    let metadata = std::fs::metadata(project_path.join("package.json")).ok()?;
+    let modified = metadata.modified().ok()?;
+    modified.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Whether a cache entry created at `cached_at` is too old to trust as of `now`.
+fn is_stale(cached_at: u64, now: u64) -> bool {
+    now.saturating_sub(cached_at) > CACHE_TTL.as_secs()
+}
+
+/// Load the cached project path, but only if it's still fresh, still exists,
+/// still passes `validate`, and `package.json` hasn't changed underneath it.
+pub fn load_cached_project_dir(validate: impl Fn(&PathBuf) -> bool) -> Option<PathBuf> {
+    let path = state_file_path()?;
+    let contents = std::fs::read_to_string(&path).ok()?;
+    let cached: CachedProject = serde_json::from_str(&contents).ok()?;
+
+    if is_stale(cached.cached_at, now_secs()) {
+        log::debug!(
+            "Project cache is stale (older than TTL {}s), falling back to full scan",
+            CACHE_TTL.as_secs()
+        );
+        return None;
+    }
+
+    if !validate(&cached.project_path) {
+        log::debug!("Cached project path no longer validates, falling back to full scan");
+        return None;
+    }
+
+    if package_json_mtime(&cached.project_path) != Some(cached.package_json_mtime) {
+        log::debug!("Cached project's package.json changed, falling back to full scan");
+        return None;
+    }
+
+    log::info!("Using cached project directory: {:?}", cached.project_path);
+    Some(cached.project_path)
+}
+
+/// Persist a freshly-resolved project path so the next launch can skip the
+/// scan entirely.
+pub fn store_cached_project_dir(project_path: &Path) {
+    let Some(state_path) = state_file_path() else {
+        return;
+    };
+    let Some(mtime) = package_json_mtime(project_path) else {
+        return;
+    };
+
+    let cached = CachedProject {
+        project_path: project_path.to_path_buf(),
+        package_json_mtime: mtime,
+        cached_at: now_secs(),
+    };
+
+    if let Some(parent) = state_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    match serde_json::to_string_pretty(&cached) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(&state_path, json) {
+                log::warn!("Failed to write project cache: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to serialize project cache: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_cache_is_not_stale() {
+        let now = 1_000_000;
+        assert!(!is_stale(now - 60, now));
+    }
+
+    #[test]
+    fn cache_past_ttl_is_stale() {
+        let now = 1_000_000;
+        assert!(is_stale(now - CACHE_TTL.as_secs() - 1, now));
+    }
+
+    #[test]
+    fn cache_at_exact_ttl_boundary_is_not_stale() {
+        let now = 1_000_000;
+        assert!(!is_stale(now - CACHE_TTL.as_secs(), now));
+    }
+
+    #[test]
+    fn package_json_mtime_reads_existing_file() {
+        let dir = std::env::temp_dir().join(format!("eliza-cache-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("package.json"), "{}").unwrap();
+
+        assert!(package_json_mtime(&dir).is_some());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn package_json_mtime_missing_file_is_none() {
+        let dir = std::env::temp_dir().join(format!("eliza-cache-test-missing-{}", std::process::id()));
+        assert_eq!(package_json_mtime(&dir), None);
+    }
+}
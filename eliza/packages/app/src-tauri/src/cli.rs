@@ -0,0 +1,45 @@
+// Command-line arguments for the Eliza Desktop launcher.
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Eliza Desktop - local trading-brain companion app.
+#[derive(Parser, Debug, Clone, Default)]
+#[command(name = "eliza-desktop", version, about)]
+pub struct Args {
+    /// Override the discovered trading-brain project directory.
+    #[arg(long, value_name = "DIR")]
+    pub project_path: Option<PathBuf>,
+
+    /// Override the port the elizaos server listens on (default: 3000).
+    #[arg(long, value_name = "PORT")]
+    pub port: Option<u16>,
+
+    /// Don't spawn a local elizaos server; attach to one that's already running.
+    #[arg(long)]
+    pub no_server: bool,
+
+    /// Block until the elizaos server reports ready before finishing setup.
+    #[arg(long)]
+    pub wait: bool,
+
+    /// Log level filter (error, warn, info, debug, trace). Overrides RUST_LOG.
+    #[arg(long, value_name = "LEVEL")]
+    pub log_level: Option<String>,
+}
+
+impl Args {
+    /// Parse CLI args, skipping the ones Tauri/webview injects on some platforms.
+    ///
+    /// macOS's Launch Services appends a `-psn_xxxxx` process-serial-number
+    /// argument when the app is opened via Finder/Dock; clap would otherwise
+    /// reject it as unrecognized and hard-crash the launch.
+    pub fn parse_args() -> Self {
+        let argv = std::env::args().filter(|arg| !arg.starts_with("-psn_"));
+        Args::parse_from(argv)
+    }
+
+    pub fn port_or_default(&self) -> u16 {
+        self.port.unwrap_or(3000)
+    }
+}
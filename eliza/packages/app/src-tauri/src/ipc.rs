@@ -0,0 +1,174 @@
+// Single-instance IPC handshake: the first launched instance listens on a
+// local socket; later launches forward their request to it and exit instead
+// of spawning a second elizaos server.
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::thread;
+
+use interprocess::local_socket::{LocalSocketListener, LocalSocketStream};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+const SOCKET_NAME: &str = "eliza-desktop.sock";
+
+/// Sent by a newly-launched instance to the already-running one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusRequest {
+    pub project_path: Option<PathBuf>,
+    pub focus: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IpcResponse {
+    Ok,
+    Error(String),
+}
+
+#[cfg(windows)]
+fn socket_name() -> String {
+    format!("\\\\.\\pipe\\{}", SOCKET_NAME)
+}
+
+#[cfg(not(windows))]
+fn socket_name() -> String {
+    format!("/tmp/{}", SOCKET_NAME)
+}
+
+/// Decides which role this process plays in the single-instance handshake.
+pub enum InstanceRole {
+    /// We're the first instance: the listener is already bound, so nothing
+    /// can steal the socket out from under us between "check" and "claim".
+    Primary(LocalSocketListener),
+    /// Another instance already owns the socket; `try_forward_to_running_instance`
+    /// should be used to hand it our request.
+    Secondary,
+}
+
+/// Atomically decide whether this process is the primary instance. Binding
+/// the socket *is* the check: `bind()` can only succeed for one process at a
+/// time, so there's no gap between "is anyone else running?" and "claim the
+/// socket" for two instances launched close together to both win. A bind
+/// failure on Unix can also mean a stale socket file left behind by a
+/// crashed instance; we only unlink and retry once we've confirmed nothing
+/// answers a connect attempt, so we never unlink a socket a live instance is
+/// using.
+pub fn acquire_instance_role() -> InstanceRole {
+    let name = socket_name();
+
+    if let Ok(listener) = LocalSocketListener::bind(name.as_str()) {
+        return InstanceRole::Primary(listener);
+    }
+
+    // Bind failed: either a live instance owns the socket, or a stale file
+    // was left behind by one that crashed without cleaning up. Probe before
+    // touching the file - only unlink and retry once we've confirmed nothing
+    // answers, so we never steal the socket out from under a live listener.
+    #[cfg(not(windows))]
+    {
+        if LocalSocketStream::connect(name.as_str()).is_err() {
+            let _ = std::fs::remove_file(&name);
+            if let Ok(listener) = LocalSocketListener::bind(name.as_str()) {
+                return InstanceRole::Primary(listener);
+            }
+        }
+    }
+
+    InstanceRole::Secondary
+}
+
+/// Try to reach an already-running instance and hand it our request.
+/// Returns `true` if another instance accepted the request (meaning this
+/// process should exit instead of starting its own server).
+pub fn try_forward_to_running_instance(request: &FocusRequest) -> bool {
+    let name = socket_name();
+    let mut stream = match LocalSocketStream::connect(name.as_str()) {
+        Ok(stream) => stream,
+        Err(_) => return false,
+    };
+
+    let payload = match serde_json::to_string(request) {
+        Ok(payload) => payload,
+        Err(e) => {
+            log::warn!("Failed to serialize focus request: {}", e);
+            return false;
+        }
+    };
+
+    if writeln!(stream, "{}", payload).is_err() {
+        return false;
+    }
+
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    match reader.read_line(&mut line) {
+        Ok(0) => false,
+        Ok(_) => matches!(
+            serde_json::from_str::<IpcResponse>(line.trim()),
+            Ok(IpcResponse::Ok)
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Start accepting handshakes from later-launched instances on `listener`
+/// (already bound by `acquire_instance_role`, before Tauri finished
+/// building). Runs for the lifetime of the app on a background thread.
+pub fn spawn_listener(app_handle: AppHandle, listener: LocalSocketListener) {
+    thread::spawn(move || {
+        for conn in listener.incoming() {
+            let conn = match conn {
+                Ok(conn) => conn,
+                Err(e) => {
+                    log::warn!("IPC connection error: {}", e);
+                    continue;
+                }
+            };
+            handle_connection(&app_handle, conn);
+        }
+    });
+}
+
+fn handle_connection(app_handle: &AppHandle, mut conn: LocalSocketStream) {
+    let mut reader = BufReader::new(&mut conn);
+    let mut line = String::new();
+    if reader.read_line(&mut line).is_err() {
+        return;
+    }
+
+    let response = match serde_json::from_str::<FocusRequest>(line.trim()) {
+        Ok(request) => {
+            focus_and_switch(app_handle, request);
+            IpcResponse::Ok
+        }
+        Err(e) => IpcResponse::Error(format!("bad request: {}", e)),
+    };
+
+    if let Ok(payload) = serde_json::to_string(&response) {
+        let _ = writeln!(conn, "{}", payload);
+    }
+}
+
+fn focus_and_switch(app_handle: &AppHandle, request: FocusRequest) {
+    log::info!("Received focus request from another instance: {:?}", request);
+
+    if let Some(window) = app_handle.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+
+    if request.focus {
+        let _ = app_handle.emit("eliza://focus-requested", &request.project_path);
+    }
+
+    // A project path means the new instance was launched against a
+    // different trading-brain project: actually switch the running server
+    // over to it instead of just telling the frontend about the request.
+    if let Some(project_path) = request.project_path {
+        let app_handle = app_handle.clone();
+        thread::spawn(move || {
+            crate::switch_project(app_handle, Some(project_path));
+        });
+    }
+}
+
@@ -5,50 +5,108 @@ use std::process::{Child, Command};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::thread;
-use std::fs::OpenOptions;
-use std::io::Write;
-use tauri::Manager;
+use tauri::{Emitter, Manager};
+
+mod cache;
+mod cli;
+mod ipc;
+mod logging;
+mod supervisor;
+mod watcher;
+
+use cli::Args;
+use ipc::FocusRequest;
+use supervisor::Supervisor;
 
 // Store the server process so we can kill it when the app closes
-static SERVER_PROCESS: once_cell::sync::Lazy<Arc<Mutex<Option<Child>>>> = 
+static SERVER_PROCESS: once_cell::sync::Lazy<Arc<Mutex<Option<Child>>>> =
     once_cell::sync::Lazy::new(|| Arc::new(Mutex::new(None)));
 
-// Log to file for debugging (works even when console is closed)
-fn log_to_file(message: &str) {
-    #[cfg(windows)]
-    {
-        if let Ok(app_data) = std::env::var("APPDATA") {
-            let log_path = PathBuf::from(&app_data)
-                .join("Eliza Desktop")
-                .join("eliza-desktop.log");
-            
-            // Create directory if it doesn't exist
-            if let Some(parent) = log_path.parent() {
-                let _ = std::fs::create_dir_all(parent);
-            }
-            
-            if let Ok(mut file) = OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&log_path)
-            {
-                let timestamp = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .map(|d| d.as_secs())
-                    .unwrap_or(0);
-                let _ = writeln!(file, "[{}] {}", timestamp, message);
-            }
-        }
+// The running supervisor, if the server was started under supervision.
+static SUPERVISOR: once_cell::sync::Lazy<Mutex<Option<Supervisor>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(None));
+
+// The project directory watcher, if the project dir was resolved. Dropping
+// the handle (on shutdown) stops the watcher thread.
+static PROJECT_WATCHER: once_cell::sync::Lazy<Mutex<Option<watcher::WatcherHandle>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(None));
+
+// The port the currently-running (or about-to-run) elizaos server is bound
+// to, so a project switch can rebuild a SpawnSpec without threading the CLI
+// port argument all the way through.
+static CURRENT_PORT: once_cell::sync::Lazy<Mutex<u16>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(3000));
+
+fn set_current_port(port: u16) {
+    *CURRENT_PORT.lock().expect("current port mutex poisoned") = port;
+}
+
+fn current_port() -> u16 {
+    *CURRENT_PORT.lock().expect("current port mutex poisoned")
+}
+
+/// Stores a freshly-started `Supervisor`, marking whatever supervisor was
+/// running before as shutting down so its monitor thread doesn't keep
+/// polling the child we're about to replace.
+pub(crate) fn set_supervisor(new: Supervisor) {
+    let old = SUPERVISOR.lock().expect("supervisor mutex poisoned").replace(new);
+    if let Some(old) = old {
+        old.mark_shutting_down();
     }
-    
-    // Always print to stderr too (visible in debug builds)
-    eprintln!("{}", message);
 }
 
-macro_rules! log_error {
-    ($($arg:tt)*) => {
-        log_to_file(&format!($($arg)*));
-    };
+/// Tears down whatever server is currently running (supervisor + child) so a
+/// caller can replace it with a freshly-spawned one, e.g. on hot-reload or a
+/// project switch. Marks the current supervisor as shutting down first so
+/// its monitor loop doesn't race to restart the child we're about to kill.
+pub(crate) fn teardown_current_server() {
+    if let Some(supervisor) = SUPERVISOR.lock().expect("supervisor mutex poisoned").as_ref() {
+        supervisor.mark_shutting_down();
+    }
+    if let Some(mut child) = SERVER_PROCESS.lock().expect("server process mutex poisoned").take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+// Serializes teardown-then-respawn so a hot-reload restart and an IPC-driven
+// project switch can't interleave: without this, both could tear down and
+// spawn concurrently, and whichever `SERVER_PROCESS` assignment loses would
+// silently drop (not kill) the other's `Child`, leaking an orphaned elizaos
+// process whose monitor thread is left polling someone else's child.
+static SWITCH_LOCK: once_cell::sync::Lazy<Mutex<()>> = once_cell::sync::Lazy::new(|| Mutex::new(()));
+
+/// Tears down the current server and starts a new one from `spec`, replacing
+/// the global supervisor and the project watcher (a new one on `watch_dir`,
+/// or none at all when `watch_dir` is `None`).
+/// The whole sequence runs under a single lock so concurrent callers (the
+/// project watcher, an IPC project switch) can't race each other.
+pub(crate) fn respawn_server(
+    app_handle: tauri::AppHandle,
+    spec: supervisor::SpawnSpec,
+    watch_dir: Option<PathBuf>,
+) -> std::io::Result<()> {
+    let _guard = SWITCH_LOCK.lock().expect("switch mutex poisoned");
+
+    teardown_current_server();
+
+    let supervisor = Supervisor::start(app_handle.clone(), SERVER_PROCESS.clone(), spec.clone())?;
+    set_supervisor(supervisor);
+
+    let handle = watch_dir
+        .map(|dir| watcher::watch_project_dir(app_handle.clone(), dir, spec))
+        .flatten();
+    *PROJECT_WATCHER.lock().expect("watcher mutex poisoned") = handle;
+
+    Ok(())
+}
+
+/// Where we keep logs and cached state: `<platform data dir>/Eliza Desktop`.
+/// Windows: `%APPDATA%\Eliza Desktop`. macOS: `~/Library/Application
+/// Support/Eliza Desktop`. Linux: `$XDG_DATA_HOME/Eliza Desktop` (or
+/// `~/.local/share/Eliza Desktop`).
+pub(crate) fn app_data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("Eliza Desktop"))
 }
 
 #[tauri::command]
@@ -57,17 +115,17 @@ fn greet(name: &str) -> String {
 }
 
 // Check if the server is running by attempting to connect to the port
-fn is_server_running() -> bool {
-    match TcpStream::connect("127.0.0.1:3000") {
+fn is_server_running(port: u16) -> bool {
+    match TcpStream::connect(("127.0.0.1", port)) {
         Ok(_) => true,
         Err(_) => false,
     }
 }
 
 // Wait for server to be ready with retry logic
-fn wait_for_server(max_retries: u32) -> bool {
+fn wait_for_server(port: u16, max_retries: u32) -> bool {
     for i in 0..max_retries {
-        if is_server_running() {
+        if is_server_running(port) {
             return true;
         }
         // Exponential backoff: 1s, 2s, 4s, 8s...
@@ -78,59 +136,130 @@ fn wait_for_server(max_retries: u32) -> bool {
 }
 
 // Find the trading-brain project directory
-fn find_project_directory() -> Option<PathBuf> {
-    log_error!("Searching for trading-brain project...");
+fn find_project_directory(cli_override: Option<&PathBuf>) -> Option<PathBuf> {
+    log::info!("Searching for trading-brain project...");
+
+    // Highest priority: explicit --project-path flag. An invalid override is
+    // a hard error, not "no override" - silently falling back to the
+    // cache/scan would risk resolving to some other (possibly stale)
+    // project instead of telling the user their flag was wrong.
+    if let Some(cli_path) = cli_override {
+        log::debug!("Checking --project-path: {:?}", cli_path);
+        return match cli_path.canonicalize() {
+            Ok(canonical_path) if validate_project_directory(&canonical_path) => {
+                log::info!("Found trading-brain via --project-path: {:?}", canonical_path);
+                Some(canonical_path)
+            }
+            Ok(canonical_path) => {
+                log::warn!(
+                    "--project-path {:?} does not look like a trading-brain project; not falling back to a cached/scanned directory",
+                    canonical_path
+                );
+                None
+            }
+            Err(e) => {
+                log::warn!(
+                    "--project-path {:?} could not be resolved ({}); not falling back to a cached/scanned directory",
+                    cli_path, e
+                );
+                None
+            }
+        };
+    }
+
+    // Next, try the TTL-invalidated cache from a previous launch, which
+    // turns the common case into a single stat instead of dozens of probes.
+    if let Some(cached) = cache::load_cached_project_dir(validate_project_directory) {
+        return Some(cached);
+    }
+
+    // Nothing cached (or it was stale) - fall back to the full scan, and
+    // cache whatever it finds for next time.
+    let found = scan_for_project_directory();
+    if let Some(ref path) = found {
+        cache::store_cached_project_dir(path);
+    }
+    found
+}
+
+// Candidate roots to look for `Civ/eliza/trading-brain` under, built from
+// the `dirs` crate so this works the same on Windows, macOS and Linux.
+fn common_location_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
 
-    // First, try environment variable (highest priority)
+    if let Some(home) = dirs::home_dir() {
+        roots.push(home.clone());
+        roots.push(home.join("Projects"));
+        roots.push(home.join("eliza"));
+    }
+    if let Some(documents) = dirs::document_dir() {
+        roots.push(documents);
+    }
+    if let Some(desktop) = dirs::desktop_dir() {
+        roots.push(desktop);
+    }
+    // XDG-style locations on Linux (and anywhere else $XDG_CONFIG_HOME is set).
+    if let Ok(xdg_config) = std::env::var("XDG_CONFIG_HOME") {
+        roots.push(PathBuf::from(xdg_config));
+    }
+    if let Some(data_local) = dirs::data_local_dir() {
+        roots.push(data_local);
+    }
+
+    roots
+}
+
+// Scans the filesystem for the trading-brain project directory: env var,
+// common dev locations, and walking up from the exe/cwd.
+fn scan_for_project_directory() -> Option<PathBuf> {
+    // Try environment variable
     if let Ok(env_path) = std::env::var("ELIZA_PROJECT_PATH") {
-        log_error!("Checking ELIZA_PROJECT_PATH: {}", env_path);
+        log::debug!("Checking ELIZA_PROJECT_PATH: {}", env_path);
         let path = PathBuf::from(env_path);
         if let Ok(canonical_path) = path.canonicalize() {
             if validate_project_directory(&canonical_path) {
-                log_error!("Found trading-brain via ELIZA_PROJECT_PATH: {:?}", canonical_path);
+                log::info!("Found trading-brain via ELIZA_PROJECT_PATH: {:?}", canonical_path);
                 return Some(canonical_path);
             }
         }
     }
 
-    // Try common development locations relative to user home
-    if let Ok(home_dir) = std::env::var("USERPROFILE") {
-        log_error!("Checking common dev locations from home: {}", home_dir);
-        let home = PathBuf::from(home_dir);
-
-        // Common locations where eliza projects might be
-        let common_locations = vec![
-            home.join("Civ").join("eliza").join("trading-brain"),
-            home.join("Documents").join("Civ").join("eliza").join("trading-brain"),
-            home.join("Desktop").join("Civ").join("eliza").join("trading-brain"),
-            home.join("Projects").join("Civ").join("eliza").join("trading-brain"),
-            home.join("eliza").join("trading-brain"),
-            home.join("Civ").join("trading-brain"),
-        ];
-
-        for location in common_locations {
-            if let Ok(canonical_path) = location.canonicalize() {
-                if validate_project_directory(&canonical_path) {
-                    log_error!("Found trading-brain in common location: {:?}", canonical_path);
-                    return Some(canonical_path);
-                }
+    // Try common development locations relative to the home/documents/desktop
+    // dirs, plus XDG-style roots on Linux.
+    log::debug!("Checking common dev locations");
+    let common_locations: Vec<PathBuf> = common_location_roots()
+        .into_iter()
+        .flat_map(|root| {
+            vec![
+                root.join("Civ").join("eliza").join("trading-brain"),
+                root.join("Civ").join("trading-brain"),
+                root.join("eliza").join("trading-brain"),
+            ]
+        })
+        .collect();
+
+    for location in common_locations {
+        if let Ok(canonical_path) = location.canonicalize() {
+            if validate_project_directory(&canonical_path) {
+                log::info!("Found trading-brain in common location: {:?}", canonical_path);
+                return Some(canonical_path);
             }
         }
     }
 
     // Try walking up from exe location (for installed apps)
     if let Ok(exe_path) = std::env::current_exe() {
-        log_error!("Exe path: {:?}", exe_path);
+        log::debug!("Exe path: {:?}", exe_path);
         if let Some(mut current) = exe_path.parent() {
             // Walk up the directory tree looking for eliza/trading-brain
             for _ in 0..15 { // Go up 15 levels max
-                log_error!("Checking directory: {:?}", current);
+                log::debug!("Checking directory: {:?}", current);
 
                 // Check if current directory contains eliza/trading-brain
                 let eliza_trading_brain = current.join("eliza").join("trading-brain");
                 if let Ok(canonical_path) = eliza_trading_brain.canonicalize() {
                     if validate_project_directory(&canonical_path) {
-                        log_error!("Found trading-brain walking up from exe: {:?}", canonical_path);
+                        log::info!("Found trading-brain walking up from exe: {:?}", canonical_path);
                         return Some(canonical_path);
                     }
                 }
@@ -139,7 +268,7 @@ fn find_project_directory() -> Option<PathBuf> {
                 let trading_brain = current.join("trading-brain");
                 if let Ok(canonical_path) = trading_brain.canonicalize() {
                     if validate_project_directory(&canonical_path) {
-                        log_error!("Found trading-brain as sibling to exe: {:?}", canonical_path);
+                        log::info!("Found trading-brain as sibling to exe: {:?}", canonical_path);
                         return Some(canonical_path);
                     }
                 }
@@ -156,7 +285,7 @@ fn find_project_directory() -> Option<PathBuf> {
 
     // Try relative to current working directory
     if let Ok(cwd) = std::env::current_dir() {
-        log_error!("Current working directory: {:?}", cwd);
+        log::debug!("Current working directory: {:?}", cwd);
 
         // Try going up from CWD
         let mut current = cwd.as_path();
@@ -164,7 +293,7 @@ fn find_project_directory() -> Option<PathBuf> {
             let eliza_trading_brain = current.join("eliza").join("trading-brain");
             if let Ok(canonical_path) = eliza_trading_brain.canonicalize() {
                 if validate_project_directory(&canonical_path) {
-                    log_error!("Found trading-brain walking up from CWD: {:?}", canonical_path);
+                    log::info!("Found trading-brain walking up from CWD: {:?}", canonical_path);
                     return Some(canonical_path);
                 }
             }
@@ -177,23 +306,7 @@ fn find_project_directory() -> Option<PathBuf> {
         }
     }
 
-    // Last resort: try some hardcoded common paths
-    let fallback_paths = vec![
-        PathBuf::from("C:\\Users\\Top Cash Pawn\\Civ\\eliza\\trading-brain"),
-        PathBuf::from("C:\\Users\\Top Cash Pawn\\Documents\\Civ\\eliza\\trading-brain"),
-        PathBuf::from("C:\\Users\\Top Cash Pawn\\Desktop\\Civ\\eliza\\trading-brain"),
-    ];
-
-    for path in fallback_paths {
-        if let Ok(canonical_path) = path.canonicalize() {
-            if validate_project_directory(&canonical_path) {
-                log_error!("Found trading-brain in fallback location: {:?}", canonical_path);
-                return Some(canonical_path);
-            }
-        }
-    }
-
-    log_error!("Could not find trading-brain project directory in any location");
+    log::warn!("Could not find trading-brain project directory in any location");
     None
 }
 
@@ -230,19 +343,19 @@ fn find_elizaos_command() -> (String, Vec<String>) {
             .output()
         {
             Ok(_) => {
-                log_error!("Found elizaos via bunx");
+                log::info!("Found elizaos via bunx");
                 return ("bunx".to_string(), vec!["--bun".to_string(), "elizaos".to_string()]);
             }
             Err(e) => {
-                log_error!("bunx not found: {}", e);
+                log::warn!("bunx not found: {}", e);
                 // Try elizaos directly
                 match Command::new("elizaos").arg("--version").output() {
                     Ok(_) => {
-                        log_error!("Found elizaos directly");
+                        log::info!("Found elizaos directly");
                         return ("elizaos".to_string(), vec![]);
                     }
                     Err(e2) => {
-                        log_error!("Warning: Could not find elizaos command: {}. Make sure it's installed: bun i -g @elizaos/cli", e2);
+                        log::warn!("Could not find elizaos command: {}. Make sure it's installed: bun i -g @elizaos/cli", e2);
                         return ("elizaos".to_string(), vec![]);
                     }
                 }
@@ -255,142 +368,225 @@ fn find_elizaos_command() -> (String, Vec<String>) {
         // On Unix-like systems, try elizaos directly
         match Command::new("elizaos").arg("--version").output() {
             Ok(_) => {
-                log_error!("Found elizaos directly");
+                log::info!("Found elizaos directly");
                 ("elizaos".to_string(), vec![])
             }
             Err(e) => {
-                log_error!("Warning: Could not find elizaos command: {}. Make sure it's installed: bun i -g @elizaos/cli", e);
+                log::warn!("Could not find elizaos command: {}. Make sure it's installed: bun i -g @elizaos/cli", e);
                 ("elizaos".to_string(), vec![])
             }
         }
     }
 }
 
+// Env vars every elizaos invocation gets, regardless of project or port.
+fn default_server_envs() -> Vec<(String, String)> {
+    vec![
+        ("ELIZA_USE_LOCAL_SERVER".to_string(), "true".to_string()),
+        ("CI".to_string(), "true".to_string()),
+        ("NO_UPDATE_CHECK".to_string(), "1".to_string()),
+        ("ELIZA_TEST_MODE".to_string(), "true".to_string()),
+        ("ELIZA_CLI_TEST_MODE".to_string(), "true".to_string()),
+        ("ELIZA_SKIP_LOCAL_CLI_DELEGATION".to_string(), "true".to_string()),
+        ("npm_config_update_notifier".to_string(), "false".to_string()),
+        ("NO_COLOR".to_string(), "true".to_string()),
+    ]
+}
+
+// Build the SpawnSpec for running elizaos against `project_dir` on `port`.
+// Shared by the initial launch and by `switch_project` so both start the
+// server the same way.
+fn build_server_spec(project_dir: Option<&PathBuf>, port: u16) -> supervisor::SpawnSpec {
+    let is_dev = std::env::var("TAURI_DEV").is_ok() || cfg!(debug_assertions);
+    let command = if is_dev { "dev" } else { "start" };
+    log::info!(
+        "Running in {} mode, using 'elizaos {}'",
+        if is_dev { "development" } else { "production" },
+        command
+    );
+
+    let (cmd_name, mut cmd_args) = find_elizaos_command();
+    cmd_args.push("--no-emoji".to_string()); // Disable emoji to avoid issues
+    cmd_args.push(command.to_string());
+
+    supervisor::SpawnSpec {
+        program: cmd_name,
+        args: cmd_args,
+        working_dir: project_dir.cloned(),
+        envs: default_server_envs(),
+        port,
+    }
+}
+
+// Switch to a different trading-brain project: resolve the new directory,
+// tear down the currently-running server/watcher, and respawn against the
+// new one. Used when a later-launched instance hands us a `--project-path`
+// via the IPC focus request.
+pub(crate) fn switch_project(app_handle: tauri::AppHandle, new_project_path: Option<PathBuf>) {
+    let project_dir = find_project_directory(new_project_path.as_ref());
+    if let Some(ref dir) = project_dir {
+        log::info!("Switching to trading-brain project at: {:?}", dir);
+    } else {
+        log::warn!("Switch requested but no trading-brain project directory could be resolved");
+        return;
+    }
+
+    let port = current_port();
+    let spec = build_server_spec(project_dir.as_ref(), port);
+
+    match respawn_server(app_handle.clone(), spec, project_dir.clone()) {
+        Ok(()) => {
+            log::info!("Eliza server restarted for switched project");
+            let _ = app_handle.emit(
+                "eliza://project-switched",
+                project_dir.as_ref().map(|p| p.to_string_lossy().to_string()),
+            );
+        }
+        Err(e) => log::error!("Failed to start Eliza server for switched project: {}", e),
+    }
+}
+
 // Shutdown server when app exits
 fn shutdown_server() {
-    log_error!("Shutting down Eliza server...");
+    log::info!("Shutting down Eliza server...");
+
+    // Stop watching the project dir first so a change event can't race a
+    // restart while we're tearing things down.
+    *PROJECT_WATCHER.lock().expect("watcher mutex poisoned") = None;
+
+    // Tell the supervisor this exit is intentional so it doesn't race to
+    // restart the child we're about to kill.
+    if let Some(supervisor) = SUPERVISOR.lock().expect("supervisor mutex poisoned").as_ref() {
+        supervisor.mark_shutting_down();
+    }
+
     match SERVER_PROCESS.lock() {
         Ok(mut guard) => {
             if let Some(ref mut child) = *guard {
                 if let Err(e) = child.kill() {
-                    log_error!("Failed to kill Eliza server: {}", e);
+                    log::error!("Failed to kill Eliza server: {}", e);
                 } else {
-                    log_error!("Eliza server shut down successfully");
+                    log::info!("Eliza server shut down successfully");
                 }
             }
             *guard = None;
         }
         Err(e) => {
-            log_error!("Failed to lock SERVER_PROCESS mutex during shutdown: {}", e);
+            log::error!("Failed to lock SERVER_PROCESS mutex during shutdown: {}", e);
         }
     }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let args = Args::parse_args();
+    logging::init(args.log_level.as_deref());
+
     // Set up panic handler to log crashes
     std::panic::set_hook(Box::new(|panic_info| {
-        let message = format!("PANIC: {:?}", panic_info);
-        log_to_file(&message);
+        logging::log_panic_or_fatal(&format!("PANIC: {:?}", panic_info));
     }));
-    
-    log_error!("Starting Eliza Desktop App...");
-    
+
+    log::info!("Starting Eliza Desktop App... (args: {:?})", args);
+
+    // Single-instance guard: binding the socket *is* the check, so there's no
+    // gap between "is another instance running?" and "claim the socket" for
+    // two instances launched close together to both think they're primary.
+    // Only the loser (bind failed) forwards its request and exits.
+    let listener = match ipc::acquire_instance_role() {
+        ipc::InstanceRole::Primary(listener) => listener,
+        ipc::InstanceRole::Secondary => {
+            let focus_request = FocusRequest {
+                project_path: args.project_path.clone(),
+                focus: true,
+            };
+            if ipc::try_forward_to_running_instance(&focus_request) {
+                log::info!("Another instance is already running; forwarded request and exiting");
+            } else {
+                log::warn!("Could not claim or reach the IPC socket; exiting");
+            }
+            return;
+        }
+    };
+
+    let port = args.port_or_default();
+    set_current_port(port);
+
     // Register cleanup for when app exits
     let app_result = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
         .invoke_handler(tauri::generate_handler![greet])
-        .setup(|app| {
-            log_error!("Tauri setup starting...");
-            
+        .setup(move |app| {
+            log::info!("Tauri setup starting...");
+
+            // Let later-launched instances find us from now on. The socket
+            // was already bound in `run()` (before this closure ever runs),
+            // so we're just starting to accept on it here.
+            ipc::spawn_listener(app.handle().clone(), listener);
+
             // Start the server if it's not already running
-            if !is_server_running() {
-                log_error!("Starting Eliza server...");
-                
+            if args.no_server {
+                log::info!("--no-server passed; attaching to an already-running server");
+            } else if !is_server_running(port) {
+                log::info!("Starting Eliza server...");
+
                 // Find the project directory
-                let project_dir = find_project_directory();
-                
+                let project_dir = find_project_directory(args.project_path.as_ref());
+
                 if let Some(ref dir) = project_dir {
-                    log_error!("Found trading-brain project at: {:?}", dir);
+                    log::info!("Found trading-brain project at: {:?}", dir);
                 } else {
-                    log_error!("Warning: Could not find trading-brain project directory");
-                    log_error!("Current exe: {:?}", std::env::current_exe());
-                    log_error!("Current dir: {:?}", std::env::current_dir());
-                    log_error!("Trying to start from current directory...");
-                }
-                
-                // Determine if we're in dev mode
-                let is_dev = std::env::var("TAURI_DEV").is_ok() || cfg!(debug_assertions);
-                let command = if is_dev { "dev" } else { "start" };
-                log_error!("Running in {} mode, using 'elizaos {}'", 
-                    if is_dev { "development" } else { "production" }, 
-                    command);
-                
-                // Find elizaos command
-                let (cmd_name, mut cmd_args) = find_elizaos_command();
-                cmd_args.push("--no-emoji".to_string()); // Disable emoji to avoid issues
-                cmd_args.push(command.to_string());
-
-                // Build the command
-                let mut cmd = Command::new(&cmd_name);
-                for arg in &cmd_args {
-                    cmd.arg(arg);
+                    log::warn!("Could not find trading-brain project directory");
+                    log::debug!("Current exe: {:?}", std::env::current_exe());
+                    log::debug!("Current dir: {:?}", std::env::current_dir());
+                    log::info!("Trying to start from current directory...");
                 }
                 
-                // Set working directory if we found the project
+                let spec = build_server_spec(project_dir.as_ref(), port);
+
                 if let Some(ref dir) = project_dir {
-                    cmd.current_dir(dir);
-                    log_error!("Setting working directory to: {:?}", dir);
+                    log::info!("Setting working directory to: {:?}", dir);
                 }
-                
-                // Set environment variables for the child process
-                cmd.env("ELIZA_USE_LOCAL_SERVER", "true");
-                // Disable update check to prevent npm dependency errors
-                cmd.env("CI", "true");
-                cmd.env("NO_UPDATE_CHECK", "1");
-                cmd.env("ELIZA_TEST_MODE", "true"); // Also skip update checks
-                cmd.env("ELIZA_CLI_TEST_MODE", "true");
-                cmd.env("ELIZA_SKIP_LOCAL_CLI_DELEGATION", "true");
-                // Prevent npm from being called
-                cmd.env("npm_config_update_notifier", "false");
-                // Disable any banner/display that might call npm
-                cmd.env("NO_COLOR", "true");
-                
-                // Start the server
-                match cmd.spawn() {
-                    Ok(child) => {
-                        // Store the process so we can kill it when the app closes
-                        match SERVER_PROCESS.lock() {
-                            Ok(mut server_guard) => {
-                                *server_guard = Some(child);
-                                log_error!("Eliza server process started");
-                                
-                                // Wait for server to be ready (in background)
-                                thread::spawn(move || {
-                                    if wait_for_server(10) {
-                                        log_error!("Eliza server is ready");
-                                    } else {
-                                        log_error!("Warning: Eliza server may not be ready yet");
-                                    }
-                                });
-                            }
-                            Err(e) => {
-                                log_error!("Failed to lock SERVER_PROCESS mutex: {}", e);
+
+                // Spawn the server under supervision so a crash gets restarted
+                // with backoff instead of silently leaving the app serverless.
+                // Hot-reload watching of the project dir is wired up as part
+                // of the same call, under the same switch lock the watcher
+                // and IPC project-switch paths reuse later.
+                match respawn_server(app.handle().clone(), spec, project_dir.clone()) {
+                    Ok(()) => {
+                        log::info!("Eliza server process started (supervised)");
+
+                        if args.wait {
+                            // --wait: block setup until the server answers
+                            if wait_for_server(port, 10) {
+                                log::info!("Eliza server is ready");
+                            } else {
+                                log::warn!("Eliza server may not be ready yet");
                             }
+                        } else {
+                            // Wait for server to be ready (in background)
+                            thread::spawn(move || {
+                                if wait_for_server(port, 10) {
+                                    log::info!("Eliza server is ready");
+                                } else {
+                                    log::warn!("Eliza server may not be ready yet");
+                                }
+                            });
                         }
-                    },
+                    }
                     Err(e) => {
-                        log_error!("Failed to start Eliza server: {}", e);
-                        log_error!("Make sure 'elizaos' is installed globally: bun i -g @elizaos/cli");
+                        log::error!("Failed to start Eliza server: {}", e);
+                        log::info!("Make sure 'elizaos' is installed globally: bun i -g @elizaos/cli");
                         if let Some(ref dir) = project_dir {
-                            log_error!("Project directory: {:?}", dir);
+                            log::info!("Project directory: {:?}", dir);
                         }
                         // Don't crash - just log the error and continue
                     }
                 };
             } else {
-                log_error!("Eliza server is already running");
+                log::info!("Eliza server is already running");
             }
             
             // Add event listener for app exit
@@ -407,14 +603,14 @@ pub fn run() {
                 }
             }
             
-            log_error!("Tauri setup complete");
+            log::info!("Tauri setup complete");
             Ok(())
         })
         .build(tauri::generate_context!());
     
     match app_result {
         Ok(app) => {
-            log_error!("Tauri app built successfully, starting...");
+            log::info!("Tauri app built successfully, starting...");
             app.run(|_app_handle, event| {
                 if let tauri::RunEvent::Exit = event {
                     shutdown_server();
@@ -422,7 +618,7 @@ pub fn run() {
             });
         }
         Err(e) => {
-            log_error!("Failed to build Tauri application: {}", e);
+            log::error!("Failed to build Tauri application: {}", e);
             // Try to show error message box on Windows
             #[cfg(windows)]
             {
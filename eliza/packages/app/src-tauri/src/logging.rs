@@ -0,0 +1,209 @@
+// Logging facade: a `log::Log` implementation that writes to both stderr and
+// a size-rotated file under the app data dir, with a level controlled by
+// `RUST_LOG` (or `--log-level`, which takes precedence).
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::app_data_dir;
+
+const LOG_FILE_NAME: &str = "eliza-desktop.log";
+/// Roll the log file once it reaches this size.
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+/// Keep at most this many rotated files (`eliza-desktop.log.1` .. `.N`).
+const MAX_ROTATED_FILES: u32 = 5;
+
+struct FileAndStderrLogger {
+    file: Mutex<Option<std::fs::File>>,
+    log_path: Option<std::path::PathBuf>,
+}
+
+impl Log for FileAndStderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let line = format!(
+            "[{}] [{}] {}",
+            timestamp,
+            record.level(),
+            record.args()
+        );
+
+        eprintln!("{}", line);
+
+        let mut guard = self.file.lock().expect("log file mutex poisoned");
+        if let Some(path) = &self.log_path {
+            if rotate_if_needed(path) {
+                // The old handle now points at the rotated-away inode;
+                // reopen a fresh file at `log_path` so writes keep landing
+                // in the active log instead of growing the rotated copy
+                // unbounded.
+                *guard = OpenOptions::new().create(true).append(true).open(path).ok();
+            }
+        }
+        if let Some(file) = guard.as_mut() {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = self.file.lock().expect("log file mutex poisoned").as_mut() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Rotates `log_path` if it has reached `MAX_LOG_BYTES`. Returns `true` if a
+/// rotation happened, meaning the caller must reopen its file handle.
+fn rotate_if_needed(log_path: &std::path::Path) -> bool {
+    let Ok(metadata) = fs::metadata(log_path) else {
+        return false;
+    };
+    if metadata.len() < MAX_LOG_BYTES {
+        return false;
+    }
+
+    // Shift eliza-desktop.log.(N-1) -> .N, ..., .log -> .log.1
+    for i in (1..MAX_ROTATED_FILES).rev() {
+        let from = rotated_path(log_path, i);
+        let to = rotated_path(log_path, i + 1);
+        if from.exists() {
+            let _ = fs::rename(&from, &to);
+        }
+    }
+    let _ = fs::rename(log_path, rotated_path(log_path, 1));
+    true
+}
+
+fn rotated_path(log_path: &std::path::Path, index: u32) -> std::path::PathBuf {
+    let mut name = log_path.as_os_str().to_os_string();
+    name.push(format!(".{}", index));
+    std::path::PathBuf::from(name)
+}
+
+/// Resolve the effective level filter: `--log-level` wins, then `RUST_LOG`,
+/// then `info`.
+fn resolve_level_filter(cli_level: Option<&str>) -> LevelFilter {
+    cli_level
+        .and_then(|s| s.parse().ok())
+        .or_else(|| std::env::var("RUST_LOG").ok().and_then(|s| s.parse().ok()))
+        .unwrap_or(LevelFilter::Info)
+}
+
+/// Initialize the global logger. Safe to call once at startup; subsequent
+/// calls are ignored by `log::set_logger`.
+pub fn init(cli_level: Option<&str>) {
+    let level = resolve_level_filter(cli_level);
+
+    let log_path = app_data_dir().map(|dir| dir.join(LOG_FILE_NAME));
+    if let Some(path) = &log_path {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+    }
+
+    let file = log_path.as_ref().and_then(|path| {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .ok()
+    });
+
+    let logger = FileAndStderrLogger {
+        file: Mutex::new(file),
+        log_path,
+    };
+
+    log::set_max_level(level);
+    if log::set_boxed_logger(Box::new(logger)).is_err() {
+        // Logger already installed (e.g. re-entrant init); nothing to do.
+        eprintln!("Logger already initialized");
+    }
+}
+
+/// Log a message at error level from contexts that can't always reach for
+/// `log::error!` directly (the panic hook).
+pub fn log_panic_or_fatal(message: &str) {
+    log::log!(Level::Error, "{}", message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `resolve_level_filter` reads the process-wide RUST_LOG env var, so
+    // serialize the tests that touch it to avoid racing each other under
+    // cargo test's default parallel execution.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn cli_level_wins_over_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("RUST_LOG", "error");
+        assert_eq!(resolve_level_filter(Some("debug")), LevelFilter::Debug);
+        std::env::remove_var("RUST_LOG");
+    }
+
+    #[test]
+    fn env_is_used_when_no_cli_level() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("RUST_LOG", "warn");
+        assert_eq!(resolve_level_filter(None), LevelFilter::Warn);
+        std::env::remove_var("RUST_LOG");
+    }
+
+    #[test]
+    fn invalid_cli_level_falls_back_to_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("RUST_LOG", "trace");
+        assert_eq!(resolve_level_filter(Some("not-a-level")), LevelFilter::Trace);
+        std::env::remove_var("RUST_LOG");
+    }
+
+    #[test]
+    fn defaults_to_info_when_nothing_set() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("RUST_LOG");
+        assert_eq!(resolve_level_filter(None), LevelFilter::Info);
+    }
+
+    #[test]
+    fn rotate_if_needed_leaves_small_file_alone() {
+        let dir = std::env::temp_dir().join(format!("eliza-log-test-small-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let log_path = dir.join("eliza-desktop.log");
+        fs::write(&log_path, b"short").unwrap();
+
+        assert!(!rotate_if_needed(&log_path));
+        assert!(log_path.exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rotate_if_needed_rolls_file_at_threshold() {
+        let dir = std::env::temp_dir().join(format!("eliza-log-test-big-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let log_path = dir.join("eliza-desktop.log");
+        fs::write(&log_path, vec![0u8; MAX_LOG_BYTES as usize]).unwrap();
+
+        assert!(rotate_if_needed(&log_path));
+        assert!(!log_path.exists());
+        assert!(rotated_path(&log_path, 1).exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
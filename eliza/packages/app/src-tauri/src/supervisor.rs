@@ -0,0 +1,157 @@
+// Supervises the elizaos child process: watches for unexpected exits and
+// restarts it with exponential backoff, tripping a circuit breaker if it
+// keeps dying.
+use std::process::{Child, Command};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter};
+
+/// Stop retrying after this many consecutive failures...
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+/// ...within this window. A restart outside the window resets the count.
+const FAILURE_WINDOW: Duration = Duration::from_secs(60);
+/// How often the monitor loop polls the child/server state.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Everything needed to (re)spawn the elizaos child exactly the way it was
+/// originally launched.
+#[derive(Clone)]
+pub struct SpawnSpec {
+    pub program: String,
+    pub args: Vec<String>,
+    pub working_dir: Option<std::path::PathBuf>,
+    pub envs: Vec<(String, String)>,
+    pub port: u16,
+}
+
+impl SpawnSpec {
+    pub fn spawn(&self) -> std::io::Result<Child> {
+        let mut cmd = Command::new(&self.program);
+        cmd.args(&self.args);
+        if let Some(dir) = &self.working_dir {
+            cmd.current_dir(dir);
+        }
+        for (key, value) in &self.envs {
+            cmd.env(key, value);
+        }
+        cmd.spawn()
+    }
+}
+
+/// Shared handle used by the rest of the app to request an intentional
+/// shutdown (so the monitor loop doesn't mistake it for a crash).
+#[derive(Clone)]
+pub struct Supervisor {
+    shutting_down: Arc<AtomicBool>,
+}
+
+impl Supervisor {
+    /// Spawn the child and start the monitor thread that keeps it alive.
+    pub fn start(
+        app_handle: AppHandle,
+        server_process: Arc<Mutex<Option<Child>>>,
+        spec: SpawnSpec,
+    ) -> std::io::Result<Self> {
+        let child = spec.spawn()?;
+        *server_process.lock().expect("server process mutex poisoned") = Some(child);
+
+        let shutting_down = Arc::new(AtomicBool::new(false));
+        let supervisor = Supervisor {
+            shutting_down: shutting_down.clone(),
+        };
+
+        thread::spawn(move || monitor_loop(app_handle, server_process, spec, shutting_down));
+
+        Ok(supervisor)
+    }
+
+    /// Mark the next child exit as intentional so the monitor loop doesn't
+    /// restart it.
+    pub fn mark_shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+}
+
+fn monitor_loop(
+    app_handle: AppHandle,
+    server_process: Arc<Mutex<Option<Child>>>,
+    spec: SpawnSpec,
+    shutting_down: Arc<AtomicBool>,
+) {
+    let mut consecutive_failures: u32 = 0;
+    let mut window_start = Instant::now();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        if shutting_down.load(Ordering::SeqCst) {
+            log::debug!("Supervisor: shutdown in progress, stopping monitor loop");
+            return;
+        }
+
+        let exited = {
+            let mut guard = server_process.lock().expect("server process mutex poisoned");
+            match guard.as_mut() {
+                Some(child) => match child.try_wait() {
+                    Ok(Some(status)) => {
+                        log::warn!("Supervisor: elizaos exited with {:?}", status);
+                        *guard = None;
+                        true
+                    }
+                    Ok(None) => false,
+                    Err(e) => {
+                        log::warn!("Supervisor: try_wait failed: {}", e);
+                        false
+                    }
+                },
+                None => false,
+            }
+        };
+
+        if !exited {
+            continue;
+        }
+
+        if shutting_down.load(Ordering::SeqCst) {
+            log::debug!("Supervisor: exit was expected (shutdown), not restarting");
+            return;
+        }
+
+        if window_start.elapsed() > FAILURE_WINDOW {
+            consecutive_failures = 0;
+            window_start = Instant::now();
+        }
+        consecutive_failures += 1;
+
+        if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+            log::error!(
+                "Supervisor: circuit breaker tripped after {} consecutive failures",
+                consecutive_failures
+            );
+            let _ = app_handle.emit("eliza://server-circuit-broken", consecutive_failures);
+            return;
+        }
+
+        let delay = Duration::from_secs(2_u64.pow((consecutive_failures - 1).min(3)));
+        log::warn!(
+            "Supervisor: restarting elizaos in {:?} (attempt {})",
+            delay, consecutive_failures
+        );
+        let _ = app_handle.emit("eliza://server-restarting", consecutive_failures);
+        thread::sleep(delay);
+
+        match spec.spawn() {
+            Ok(child) => {
+                *server_process.lock().expect("server process mutex poisoned") = Some(child);
+                log::info!("Supervisor: elizaos restarted");
+                let _ = app_handle.emit("eliza://server-restarted", spec.port);
+            }
+            Err(e) => {
+                log::error!("Supervisor: failed to respawn elizaos: {}", e);
+            }
+        }
+    }
+}
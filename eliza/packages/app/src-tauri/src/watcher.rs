@@ -0,0 +1,208 @@
+// Watches the project directory for changes to config/character files and
+// triggers a graceful server restart so the user doesn't have to bounce the
+// desktop app by hand.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter};
+
+use crate::supervisor::SpawnSpec;
+
+/// Coalesce bursts of saves (editors often emit several events per save)
+/// into a single restart.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Only these extensions/file names trigger a restart; everything else
+/// (build artifacts, logs, node_modules churn) is ignored.
+const WATCHED_EXTENSIONS: &[&str] = &["json", "env", "ts", "js"];
+const WATCHED_FILE_NAMES: &[&str] = &["package.json", ".env"];
+
+/// Directory names we never want to watch or react to, even though a
+/// recursive watch would otherwise cover them: dependency/build churn in
+/// these trees fires constantly and none of it is project configuration.
+const EXCLUDED_DIR_NAMES: &[&str] = &["node_modules", ".git", "dist", "build", "target", ".next"];
+
+fn is_excluded_path(path: &Path) -> bool {
+    path.components().any(|c| {
+        c.as_os_str()
+            .to_str()
+            .map(|s| EXCLUDED_DIR_NAMES.contains(&s))
+            .unwrap_or(false)
+    })
+}
+
+fn is_relevant_path(path: &Path) -> bool {
+    if is_excluded_path(path) {
+        return false;
+    }
+    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+        if WATCHED_FILE_NAMES.contains(&name) {
+            return true;
+        }
+    }
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| WATCHED_EXTENSIONS.contains(&ext))
+        .unwrap_or(false)
+}
+
+/// Start watching `project_dir` on its own thread. Returns a handle whose
+/// `Drop` stops the watcher, so callers can just let it go out of scope on
+/// `WindowEvent::CloseRequested`.
+pub fn watch_project_dir(
+    app_handle: AppHandle,
+    project_dir: PathBuf,
+    spec: SpawnSpec,
+) -> Option<WatcherHandle> {
+    let (tx, rx) = channel();
+
+    let mut watcher: RecommendedWatcher = match notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    }) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            log::error!("Failed to create project watcher: {}", e);
+            return None;
+        }
+    };
+
+    // Don't hand a single recursive watch the whole project root: that walks
+    // into node_modules/.git/dist/etc. too, which can blow past platform
+    // inotify-watch limits and trigger restart storms from dependency churn.
+    // Watch the root non-recursively for top-level file changes, then watch
+    // each non-excluded top-level directory recursively.
+    if let Err(e) = watcher.watch(&project_dir, RecursiveMode::NonRecursive) {
+        log::error!(
+            "Failed to watch project directory {:?}: {}",
+            project_dir, e
+        );
+        return None;
+    }
+
+    if let Ok(entries) = fs::read_dir(&project_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            if is_excluded_path(&path) {
+                continue;
+            }
+            if let Err(e) = watcher.watch(&path, RecursiveMode::Recursive) {
+                log::warn!("Failed to watch subdirectory {:?}: {}", path, e);
+            }
+        }
+    }
+
+    log::info!("Watching project directory for changes: {:?}", project_dir);
+
+    let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let stop_for_thread = stop.clone();
+
+    thread::spawn(move || {
+        // Keep the notify watcher alive for as long as this thread runs;
+        // dropping it would stop event delivery and disconnect `rx`.
+        let _watcher = watcher;
+        let mut pending_change: Option<PathBuf> = None;
+        let mut last_event = Instant::now();
+
+        loop {
+            if stop_for_thread.load(std::sync::atomic::Ordering::SeqCst) {
+                log::debug!("Project watcher stopping");
+                return;
+            }
+
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(Ok(event)) => {
+                    if let Some(path) = event.paths.into_iter().find(|p| is_relevant_path(p)) {
+                        pending_change = Some(path);
+                        last_event = Instant::now();
+                    }
+                }
+                Ok(Err(e)) => {
+                    log::warn!("Project watcher error: {}", e);
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Some(path) = pending_change.take() {
+                        if last_event.elapsed() >= DEBOUNCE {
+                            handle_change(&app_handle, &path, &spec);
+                        } else {
+                            // Still within the debounce window; keep waiting.
+                            pending_change = Some(path);
+                        }
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    log::warn!("Project watcher channel disconnected, stopping");
+                    return;
+                }
+            }
+        }
+    });
+
+    Some(WatcherHandle { stop })
+}
+
+fn handle_change(app_handle: &AppHandle, changed_path: &Path, spec: &SpawnSpec) {
+    log::info!("Relevant project change detected: {:?}", changed_path);
+    let _ = app_handle.emit("eliza://project-changed", changed_path.to_string_lossy().to_string());
+
+    // Goes through the same switch-locked teardown-then-respawn path as an
+    // IPC-driven project switch, so the two can't race each other and leak
+    // an orphaned elizaos child.
+    let watch_dir = spec.working_dir.clone();
+    match crate::respawn_server(app_handle.clone(), spec.clone(), watch_dir) {
+        Ok(()) => log::info!("Server restarted after project change"),
+        Err(e) => log::error!("Failed to restart server after project change: {}", e),
+    }
+}
+
+/// Stops the watcher thread when dropped.
+pub struct WatcherHandle {
+    stop: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl Drop for WatcherHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relevant_extensions_are_watched() {
+        assert!(is_relevant_path(Path::new("/proj/.env")));
+        assert!(is_relevant_path(Path::new("/proj/character.json")));
+        assert!(is_relevant_path(Path::new("/proj/src/index.ts")));
+        assert!(is_relevant_path(Path::new("/proj/script.js")));
+    }
+
+    #[test]
+    fn irrelevant_extensions_are_ignored() {
+        assert!(!is_relevant_path(Path::new("/proj/README.md")));
+        assert!(!is_relevant_path(Path::new("/proj/eliza-desktop.log")));
+    }
+
+    #[test]
+    fn excluded_directories_are_ignored_regardless_of_extension() {
+        assert!(!is_relevant_path(Path::new("/proj/node_modules/pkg/index.js")));
+        assert!(!is_relevant_path(Path::new("/proj/.git/config.json")));
+        assert!(!is_relevant_path(Path::new("/proj/dist/bundle.js")));
+        assert!(!is_relevant_path(Path::new("/proj/target/debug/build.json")));
+    }
+
+    #[test]
+    fn is_excluded_path_matches_any_path_component() {
+        assert!(is_excluded_path(Path::new("/proj/node_modules")));
+        assert!(is_excluded_path(Path::new("node_modules/pkg")));
+        assert!(!is_excluded_path(Path::new("/proj/src/index.ts")));
+    }
+}